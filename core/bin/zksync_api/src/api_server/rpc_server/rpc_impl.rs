@@ -3,10 +3,11 @@ use std::collections::HashMap;
 use jsonrpc_core::{Error, Result};
 use num::BigUint;
 // Workspace uses
+use zksync_crypto::Fr;
 use zksync_types::{
     helpers::closest_packable_fee_amount,
     tx::{TxEthSignature, TxHash},
-    Address, Token, TokenLike, TxFeeTypes, ZkSyncTx,
+    AccountId, Address, BlockNumber, Token, TokenLike, TxFeeTypes, ZkSyncTx,
 };
 
 // Local uses
@@ -15,6 +16,72 @@ use bigdecimal::BigDecimal;
 
 use super::{error::*, types::*, RpcApp};
 
+/// Serialize a field element as a `0x`-prefixed hex string for proof transport.
+fn fr_to_hex(value: &Fr) -> String {
+    use zksync_crypto::ff::to_hex;
+    format!("0x{}", to_hex(value))
+}
+
+/// Build the conflict graph from per-transaction access lists and return the
+/// independent components as lists of original indices.
+///
+/// Two transactions conflict when their declared account sets intersect; a
+/// transaction that omits its access list is treated as touching every account
+/// and therefore merges with everything, which is the sequential fallback. Each
+/// returned component preserves the original order of its transactions so the
+/// total ordering of conflicting transactions is kept intact.
+fn partition_independent(access_lists: &[Option<Vec<AccountId>>]) -> Vec<Vec<usize>> {
+    let n = access_lists.len();
+    let mut parent: Vec<usize> = (0..n).collect();
+
+    fn find(parent: &mut Vec<usize>, mut x: usize) -> usize {
+        while parent[x] != x {
+            parent[x] = parent[parent[x]];
+            x = parent[x];
+        }
+        x
+    }
+
+    // Map each touched account to the first transaction that declared it and
+    // union transactions that share an account.
+    let mut owner: HashMap<AccountId, usize> = HashMap::new();
+    for (i, access_list) in access_lists.iter().enumerate() {
+        if let Some(accounts) = access_list {
+            for account in accounts {
+                match owner.get(account) {
+                    Some(&j) => {
+                        let a = find(&mut parent, i);
+                        let b = find(&mut parent, j);
+                        parent[a] = b;
+                    }
+                    None => {
+                        owner.insert(*account, i);
+                    }
+                }
+            }
+        }
+    }
+
+    // An access-list-less transaction is treated as touching every account, so
+    // it conflicts with all others. A single such transaction therefore forces
+    // the whole batch into one component and the sequential fallback — union it
+    // into every other transaction, not just the other unscoped ones.
+    if let Some(first) = access_lists.iter().position(|al| al.is_none()) {
+        let root = find(&mut parent, first);
+        for i in 0..n {
+            let a = find(&mut parent, i);
+            parent[a] = root;
+        }
+    }
+
+    let mut components: HashMap<usize, Vec<usize>> = HashMap::new();
+    for i in 0..n {
+        let root = find(&mut parent, i);
+        components.entry(root).or_default().push(i);
+    }
+    components.into_iter().map(|(_, group)| group).collect()
+}
+
 impl RpcApp {
     pub async fn _impl_account_info(self, address: Address) -> Result<AccountInfoResp> {
         use std::time::Instant;
@@ -43,6 +110,122 @@ impl RpcApp {
         })
     }
 
+    /// Light-client variant of [`Self::_impl_account_info`]: for each requested
+    /// token it returns the balance in the balance tree at `block_number` and
+    /// the authentication path through that tree up to the state root
+    /// committed in `block_number`. A caller can recompute the root from the
+    /// leaf and siblings and compare it against the root anchored on L1, so a
+    /// balance can be trusted without trusting this server.
+    pub async fn _impl_account_info_with_proof(
+        self,
+        address: Address,
+        block_number: BlockNumber,
+        tokens: Vec<TokenLike>,
+    ) -> Result<AccountInfoWithProofResp> {
+        let account_state = self.get_account_state(&address).await?;
+        let account_id = account_state.account_id.ok_or_else(|| {
+            Error::invalid_params("Account does not exist in the committed state")
+        })?;
+
+        let mut storage = self.access_storage().await?;
+
+        // The balance tree is reconstructed at the requested block so the
+        // returned siblings hash up to exactly the root committed there.
+        let tree = storage
+            .chain()
+            .account_schema()
+            .account_balance_tree(account_id, block_number)
+            .await
+            .map_err(|err| {
+                log::warn!(
+                    "[{}:{}:{}] Internal Server Error: '{}'; input: {}",
+                    file!(),
+                    line!(),
+                    column!(),
+                    err,
+                    address
+                );
+                Error::internal_error()
+            })?;
+
+        let mut balance_proofs = Vec::with_capacity(tokens.len());
+        for token in tokens {
+            let token = self.tx_sender.token_info_from_id(token).await?;
+            let path = tree.merkle_path(token.id);
+            balance_proofs.push(BalanceProof {
+                token_id: token.id,
+                balance: tree.balance(token.id),
+                // Sibling hashes from the leaf up to the root, leaf-first.
+                merkle_path: path.siblings.iter().map(fr_to_hex).collect(),
+                leaf_hash: fr_to_hex(&path.leaf_hash),
+            });
+        }
+
+        Ok(AccountInfoWithProofResp {
+            address,
+            id: Some(account_id),
+            block_number,
+            state_root: fr_to_hex(&tree.root_hash()),
+            balances: balance_proofs,
+        })
+    }
+
+    /// Compact header-chain style checkpoint endpoint: verified state roots at
+    /// fixed block intervals. A thin client syncs these cheaply and then
+    /// validates any account against the nearest checkpoint with
+    /// [`Self::_impl_account_info_with_proof`].
+    pub async fn _impl_state_root_checkpoints(
+        self,
+        from_block: BlockNumber,
+        to_block: BlockNumber,
+        interval: u32,
+    ) -> Result<Vec<StateRootCheckpoint>> {
+        if interval == 0 {
+            return Err(Error {
+                code: RpcErrorCodes::IncorrectTx.into(),
+                message: "Checkpoint interval must be non-zero".to_string(),
+                data: None,
+            });
+        }
+
+        let mut storage = self.access_storage().await?;
+
+        let mut checkpoints = Vec::new();
+        let mut block = from_block;
+        while block <= to_block {
+            if let Some(root) = storage
+                .chain()
+                .block_schema()
+                .verified_state_root(block)
+                .await
+                .map_err(|err| {
+                    log::warn!(
+                        "[{}:{}:{}] Internal Server Error: '{}'; input: {}",
+                        file!(),
+                        line!(),
+                        column!(),
+                        err,
+                        block
+                    );
+                    Error::internal_error()
+                })?
+            {
+                checkpoints.push(StateRootCheckpoint {
+                    block_number: block,
+                    state_root: fr_to_hex(&root),
+                });
+            }
+            // Stop before `block + interval` wraps past u32::MAX so a range
+            // ending near the maximum block number cannot panic in debug.
+            block = match block.checked_add(interval) {
+                Some(next) => next,
+                None => break,
+            };
+        }
+
+        Ok(checkpoints)
+    }
+
     pub async fn _impl_ethop_info(self, serial_id: u32) -> Result<ETHOpInfoResp> {
         let executed_op = self.get_executed_priority_operation(serial_id).await?;
         Ok(if let Some(executed_op) = executed_op {
@@ -114,6 +297,53 @@ impl RpcApp {
             .map_err(Error::from)
     }
 
+    /// Access-list aware batch submission (EIP-2930 style). Each entry may
+    /// declare the account ids it touches; the batch is partitioned into
+    /// independent components whose per-transaction signatures are verified
+    /// concurrently, while transactions that share an account (or omit their
+    /// access list) stay in a single component.
+    pub async fn _impl_submit_txs_batch_with_access_list(
+        self,
+        txs: Vec<TxWithAccessList>,
+        eth_signature: Option<TxEthSignature>,
+    ) -> Result<Vec<TxHash>> {
+        let access_lists: Vec<Option<Vec<AccountId>>> =
+            txs.iter().map(|tx| tx.access_list.clone()).collect();
+        let components = partition_independent(&access_lists);
+
+        // Verify the per-transaction (L2) signatures component-by-component.
+        // Components touch disjoint account sets and L2 signatures are
+        // independent of batch ordering, so the verification fans out and runs
+        // concurrently as a fast-fail pre-check.
+        let verifications = components.into_iter().map(|component| {
+            let this = self.clone();
+            let batch: Vec<TxWithSignature> = component
+                .iter()
+                .map(|&idx| TxWithSignature {
+                    tx: txs[idx].tx.clone(),
+                    signature: txs[idx].signature.clone(),
+                })
+                .collect();
+            async move { this.tx_sender.verify_txs_batch_signatures(&batch).await }
+        });
+        for result in futures::future::join_all(verifications).await {
+            result.map_err(Error::from)?;
+        }
+
+        // Execute the whole batch through a single `submit_txs_batch` call. The
+        // batch-level `eth_signature` is computed over the hashes of *all* txs,
+        // and `submit_txs_batch` recomputes that message from the batch it is
+        // given, so it must see the complete batch; submitting per-component
+        // sub-batches would invalidate the signature and break the caller's
+        // all-or-nothing guarantee (one component committing while another
+        // fails). The partition above only parallelizes verification.
+        let txs = txs.into_iter().map(|tx| (tx.tx, tx.signature)).collect();
+        self.tx_sender
+            .submit_txs_batch(txs, eth_signature)
+            .await
+            .map_err(Error::from)
+    }
+
     pub async fn _impl_contract_address(self) -> Result<ContractAddressResp> {
         let mut storage = self.access_storage().await?;
         let config = storage.config_schema().load_config().await.map_err(|err| {
@@ -196,17 +426,20 @@ impl RpcApp {
 
         let ticker_request_sender = self.tx_sender.ticker_requests.clone();
 
-        let mut total_fee = BigUint::from(0u32);
-
-        for (tx_type, address) in tx_types.iter().zip(addresses.iter()) {
-            total_fee += Self::ticker_request(
+        // Independent fee/ticker lookups can run concurrently instead of
+        // awaiting one request at a time; the sum is order-independent.
+        let requests = tx_types.iter().zip(addresses.iter()).map(|(tx_type, address)| {
+            Self::ticker_request(
                 ticker_request_sender.clone(),
                 tx_type.clone(),
                 *address,
                 token.clone(),
             )
-            .await?
-            .total_fee;
+        });
+
+        let mut total_fee = BigUint::from(0u32);
+        for fee in futures::future::join_all(requests).await {
+            total_fee += fee?.total_fee;
         }
         // Sum of transactions can be unpackable
         total_fee = closest_packable_fee_amount(&total_fee);
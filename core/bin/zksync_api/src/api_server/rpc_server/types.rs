@@ -0,0 +1,61 @@
+// External uses
+use num::BigUint;
+use serde::{Deserialize, Serialize};
+// Workspace uses
+use zksync_types::{
+    tx::TxEthSignature, AccountId, Address, BlockNumber, TokenId, ZkSyncTx,
+};
+use zksync_utils::BigUintSerdeAsRadix10Str;
+
+/// Merkle-inclusion proof for a single token balance against the zkSync balance
+/// tree committed in a given block. A light client recomputes the root by
+/// folding `leaf_hash` with the `merkle_path` siblings and compares it against
+/// the root anchored on L1.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BalanceProof {
+    pub token_id: TokenId,
+    #[serde(with = "BigUintSerdeAsRadix10Str")]
+    pub balance: BigUint,
+    /// Sibling hashes from the leaf up to the root, leaf-first, hex-encoded.
+    pub merkle_path: Vec<String>,
+    /// Hex-encoded hash of the balance leaf the path authenticates.
+    pub leaf_hash: String,
+}
+
+/// Light-client response for `account_info_with_proof`: the requested balances
+/// at `block_number`, each with its authentication path to that block's state root.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountInfoWithProofResp {
+    pub address: Address,
+    pub id: Option<AccountId>,
+    pub block_number: BlockNumber,
+    /// Hex-encoded state root committed in `block_number`.
+    pub state_root: String,
+    pub balances: Vec<BalanceProof>,
+}
+
+/// A batch-submission entry carrying an optional EIP-2930 style access list:
+/// the account ids the transaction touches. When present on every entry the
+/// server can partition the batch into non-conflicting components and validate
+/// them concurrently; when absent, the transaction conflicts with all others
+/// and the batch falls back to sequential validation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TxWithAccessList {
+    pub tx: ZkSyncTx,
+    pub signature: Option<TxEthSignature>,
+    /// Account ids (from/to) the transaction touches, if declared.
+    pub access_list: Option<Vec<AccountId>>,
+}
+
+/// A verified state root at a checkpoint block. Thin clients sync these cheaply
+/// and validate any account against the nearest checkpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StateRootCheckpoint {
+    pub block_number: BlockNumber,
+    /// Hex-encoded verified state root at `block_number`.
+    pub state_root: String,
+}
@@ -0,0 +1,307 @@
+//! Threshold EdDSA (FROST) signing for `TransferTx` over AltBabyJubjub.
+//!
+//! An `n`-of-`m` account Shamir-shares its spending secret `s` so that signer
+//! `i` holds a share `s_i`, while the group public key `PK = s·G` (with
+//! `G = FixedGenerators::SpendingKeyGenerator`) is the same key the chain
+//! already knows. The two signing rounds below produce a single aggregate
+//! JubJub EdDSA signature that `PublicKey::verify_for_raw_message` accepts
+//! unchanged — the chain sees one ordinary signature.
+//!
+//! Cofactor note: AltBabyJubjub has cofactor 8. The group nonce `R` is checked
+//! by multiplying it by the prime subgroup order, and any `R` outside the
+//! prime-order subgroup is rejected before a signature share or an aggregate
+//! signature is emitted.
+
+use ff::{Field, PrimeField};
+use rand::Rng;
+use sapling_crypto::jubjub::{edwards, FixedGenerators, JubjubEngine, JubjubParams, Unknown};
+
+use super::params;
+use super::tx::TxSignature;
+use super::{Engine, Fr};
+
+type Fs = <Engine as JubjubEngine>::Fs;
+type Point = edwards::Point<Engine, Unknown>;
+
+/// Identifier of a signer within the participant set. Identifiers are the
+/// non-zero Shamir evaluation points, so they must be distinct and non-zero.
+pub type SignerId = u32;
+
+fn generator(p_g: FixedGenerators) -> &'static edwards::Point<Engine, sapling_crypto::jubjub::PrimeOrder> {
+    params::JUBJUB_PARAMS.generator(p_g)
+}
+
+fn fs_from_u64(x: u64) -> Fs {
+    Fs::from_str(&x.to_string()).expect("u64 fits into Fs")
+}
+
+/// A single signer's long-lived key material: its Shamir share and identifier.
+#[derive(Clone)]
+pub struct SignerShare {
+    pub id: SignerId,
+    pub share: Fs,
+}
+
+/// Round-1 output published by a signer: the two nonce commitments.
+#[derive(Clone)]
+pub struct Round1Commitment {
+    pub id: SignerId,
+    pub d: Point,
+    pub e: Point,
+}
+
+/// Round-1 secret state a signer keeps until round 2.
+#[derive(Clone)]
+pub struct Round1Secret {
+    pub id: SignerId,
+    pub d: Fs,
+    pub e: Fs,
+}
+
+/// Round-2 output returned by a signer to the coordinator.
+#[derive(Clone)]
+pub struct Round2Share {
+    pub id: SignerId,
+    pub z: Fs,
+}
+
+/// Round 1: sample the nonce scalars `(d_i, e_i)` and publish `D_i = d_i·G`,
+/// `E_i = e_i·G`. The returned secret must be fed back into [`sign_round2`].
+pub fn sign_round1<R: Rng>(
+    id: SignerId,
+    p_g: FixedGenerators,
+    rng: &mut R,
+) -> (Round1Commitment, Round1Secret) {
+    let g = generator(p_g);
+    let d = rng.gen();
+    let e = rng.gen();
+    let commitment = Round1Commitment {
+        id,
+        d: g.mul(d, &params::JUBJUB_PARAMS),
+        e: g.mul(e, &params::JUBJUB_PARAMS),
+    };
+    (commitment, Round1Secret { id, d, e })
+}
+
+/// Binding factor `ρ_i = H(i ‖ m ‖ B)` reduced into `Fs`.
+fn binding_factor(id: SignerId, message: &[u8], commitments: &[Round1Commitment]) -> Fs {
+    let mut input = Vec::new();
+    input.extend_from_slice(&id.to_le_bytes());
+    input.extend_from_slice(message);
+    for c in commitments {
+        input.extend_from_slice(&c.id.to_le_bytes());
+        append_point(&mut input, &c.d);
+        append_point(&mut input, &c.e);
+    }
+    hash_to_fs(&input)
+}
+
+/// Challenge `c = H*(R̄ ‖ m)` computed with the *exact* hash construction that
+/// `PublicKey::verify_for_raw_message` uses, so the aggregate signature verifies
+/// on-chain unchanged. That is Blake2b-512 personalized `b"Zcash_RedJubjubH"`
+/// over the compressed `R` encoding followed by the message, reduced into `Fs`
+/// via `ToUniform`. The public key is *not* hashed — this EdDSA variant commits
+/// to `R̄ ‖ m` only, matching the library and the in-circuit gadget.
+fn challenge(r: &Point, message: &[u8]) -> Fs {
+    use sapling_crypto::jubjub::ToUniform;
+    let mut r_bytes = Vec::new();
+    r.write(&mut r_bytes).expect("serialize R");
+    let mut hasher = blake2_rfc::blake2b::Blake2b::with_params(64, &[], &[], b"Zcash_RedJubjubH");
+    hasher.update(&r_bytes);
+    hasher.update(message);
+    let hash = hasher.finalize();
+    Fs::to_uniform(hash.as_bytes())
+}
+
+/// Lagrange coefficient `λ_i` for signer `id` over the chosen signer set,
+/// evaluated at `0` (the secret lives at the constant term of the polynomial).
+fn lagrange_coefficient(id: SignerId, signer_set: &[SignerId]) -> Fs {
+    let xi = fs_from_u64(id as u64);
+    let mut num = Fs::one();
+    let mut den = Fs::one();
+    for &other in signer_set {
+        if other == id {
+            continue;
+        }
+        let xj = fs_from_u64(other as u64);
+        num.mul_assign(&xj);
+        let mut diff = xj;
+        diff.sub_assign(&xi);
+        den.mul_assign(&diff);
+    }
+    let den_inv = den.inverse().expect("distinct signer ids keep denominator invertible");
+    num.mul_assign(&den_inv);
+    num
+}
+
+/// Aggregate group nonce `R = Σ_i (D_i + ρ_i·E_i)` over the commitment list.
+fn group_nonce(commitments: &[Round1Commitment], message: &[u8]) -> Point {
+    let mut r = Point::zero();
+    for c in commitments {
+        let rho = binding_factor(c.id, message, commitments);
+        let bound = c.e.mul(rho, &params::JUBJUB_PARAMS);
+        r = r.add(&c.d, &params::JUBJUB_PARAMS).add(&bound, &params::JUBJUB_PARAMS);
+    }
+    r
+}
+
+/// Round 2: each signer returns `z_i = d_i + ρ_i·e_i + λ_i·s_i·c`.
+pub fn sign_round2(
+    secret: &Round1Secret,
+    share: &SignerShare,
+    message: &[u8],
+    commitments: &[Round1Commitment],
+) -> Result<Round2Share, String> {
+    let signer_set: Vec<SignerId> = commitments.iter().map(|c| c.id).collect();
+    let r = group_nonce(commitments, message);
+    reject_non_prime_order(&r)?;
+
+    let rho = binding_factor(secret.id, message, commitments);
+    let c = challenge(&r, message);
+    let lambda = lagrange_coefficient(share.id, &signer_set);
+
+    // z_i = d_i + ρ_i·e_i + λ_i·s_i·c
+    let mut z = secret.e;
+    z.mul_assign(&rho);
+    z.add_assign(&secret.d);
+
+    let mut signer_term = lambda;
+    signer_term.mul_assign(&share.share);
+    signer_term.mul_assign(&c);
+    z.add_assign(&signer_term);
+
+    Ok(Round2Share { id: secret.id, z })
+}
+
+/// Coordinator step: sum the per-signer `z_i` into `z = Σ z_i` and assemble
+/// the aggregate signature `TxSignature{ r_x, r_y, s = z }` from `R`.
+pub fn aggregate(
+    message: &[u8],
+    commitments: &[Round1Commitment],
+    shares: &[Round2Share],
+) -> Result<TxSignature, String> {
+    let r = group_nonce(commitments, message);
+    reject_non_prime_order(&r)?;
+
+    let mut z = Fs::zero();
+    for s in shares {
+        z.add_assign(&s.z);
+    }
+
+    let (r_x, r_y) = r.into_xy();
+    Ok(TxSignature {
+        r_x,
+        r_y,
+        s: encode_fs_into_fr(z),
+    })
+}
+
+/// Reject any point outside the prime-order subgroup. Multiplying by the full
+/// subgroup order `l` (the `Fs` modulus) yields the identity for a genuine
+/// subgroup member; a small-order-only check (`cofactor · P == 0`) would let a
+/// point `Q + T` with `Q` prime-order and `T` of order 8 slip through.
+fn reject_non_prime_order(p: &Point) -> Result<(), String> {
+    if p.mul(Fs::char(), &params::JUBJUB_PARAMS) != Point::zero() {
+        return Err("group nonce R is not in the prime-order subgroup".to_string());
+    }
+    Ok(())
+}
+
+/// Convert an `Fs` scalar into the `Fr` field element that `TxSignature` stores.
+///
+/// The baseline only ships `encode_fr_into_fs` (the `Fr → Fs` direction used by
+/// `TxSignature::to_jubjub_eddsa`); this is its inverse. The `Fs` modulus is
+/// smaller than the `Fr` modulus, so every scalar round-trips through the
+/// little-endian representation without reduction.
+fn encode_fs_into_fr(fs: Fs) -> Fr {
+    use ff::{PrimeField, PrimeFieldRepr};
+    let mut buf = Vec::new();
+    fs.into_repr().write_le(&mut buf).expect("write Fs repr");
+    let mut repr = <Fr as PrimeField>::Repr::default();
+    repr.read_le(&buf[..]).expect("read Fr repr");
+    Fr::from_repr(repr).expect("Fs value fits into Fr")
+}
+
+fn append_point(buf: &mut Vec<u8>, p: &Point) {
+    use ff::PrimeFieldRepr;
+    let (x, y) = p.into_xy();
+    x.into_repr().write_le(&mut *buf).expect("write Fr x");
+    y.into_repr().write_le(&mut *buf).expect("write Fr y");
+}
+
+/// Hash to an `Fs` scalar for the FROST-internal binding factor. This is a
+/// protocol-private domain (`b"FROSTjj_"`) separate from the on-chain EdDSA
+/// challenge; it only has to be consistent between round 2 and aggregation.
+fn hash_to_fs(input: &[u8]) -> Fs {
+    use sapling_crypto::jubjub::ToUniform;
+    let mut hasher = blake2_rfc::blake2b::Blake2b::with_params(64, &[], &[], b"FROSTjj_");
+    hasher.update(input);
+    let hash = hasher.finalize();
+    Fs::to_uniform(hash.as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{SeedableRng, XorShiftRng};
+    use sapling_crypto::eddsa::{PrivateKey, PublicKey};
+
+    /// Horner evaluation of a Shamir polynomial (constant term = the secret).
+    fn poly_eval(coeffs: &[Fs], x: Fs) -> Fs {
+        let mut acc = Fs::zero();
+        for c in coeffs.iter().rev() {
+            acc.mul_assign(&x);
+            acc.add_assign(c);
+        }
+        acc
+    }
+
+    /// End-to-end FROST round trip: the aggregate signature produced from
+    /// independent Shamir shares must verify as an ordinary EdDSA signature
+    /// under the group public key via `verify_for_raw_message` — exactly the
+    /// path the chain uses — confirming the challenge construction matches.
+    #[test]
+    fn aggregate_signature_verifies_for_raw_message() {
+        let p_g = FixedGenerators::SpendingKeyGenerator;
+        let mut rng = XorShiftRng::from_seed([0x3dbe_6258, 0x8d31_3d76, 0x3237_db17, 0xe5bc_0654]);
+
+        // 2-of-3 account: a random degree-1 polynomial with the secret at x = 0.
+        let secret: Fs = rng.gen();
+        let coeffs = [secret, rng.gen()];
+        let ids: [SignerId; 2] = [1, 2];
+        let shares: Vec<SignerShare> = ids
+            .iter()
+            .map(|&id| SignerShare {
+                id,
+                share: poly_eval(&coeffs, fs_from_u64(id as u64)),
+            })
+            .collect();
+
+        let privkey = PrivateKey::<Engine>(secret);
+        let pk = PublicKey::from_private(&privkey, p_g, &params::JUBJUB_PARAMS);
+
+        // Exactly `max_message_size` bytes so any in-library padding is a no-op.
+        let message = b"frost round-trip";
+
+        // Round 1: each signer publishes its nonce commitments.
+        let mut commitments = Vec::new();
+        let mut round1_secrets = Vec::new();
+        for &id in ids.iter() {
+            let (commitment, secret) = sign_round1(id, p_g, &mut rng);
+            commitments.push(commitment);
+            round1_secrets.push(secret);
+        }
+
+        // Round 2: each signer returns its partial signature.
+        let round2: Vec<Round2Share> = round1_secrets
+            .iter()
+            .zip(shares.iter())
+            .map(|(secret, share)| sign_round2(secret, share, message, &commitments).unwrap())
+            .collect();
+
+        // Aggregate and verify against the group public key.
+        let sig = aggregate(message, &commitments, &round2).unwrap();
+        let signature = sig.to_jubjub_eddsa().expect("aggregate signature parses");
+        assert!(pk.verify_for_raw_message(message, &signature, p_g, &params::JUBJUB_PARAMS, 16));
+    }
+}
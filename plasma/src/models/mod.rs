@@ -0,0 +1,18 @@
+//! Core data models shared across the plasma crate.
+
+pub mod circuit;
+pub mod frost;
+pub mod params;
+pub mod tx;
+
+use pairing::bn256::Bn256;
+use ff::ScalarEngine;
+
+/// The pairing engine the whole crate is instantiated over.
+pub type Engine = Bn256;
+/// Scalar field of [`Engine`].
+pub type Fr = <Engine as ScalarEngine>::Fr;
+/// EdDSA public key over the embedded AltBabyJubjub curve.
+pub type PublicKey = sapling_crypto::eddsa::PublicKey<Engine>;
+
+pub use self::tx::{DepositTx, ExitTx, TransferTx, TxSignature};